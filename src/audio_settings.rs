@@ -0,0 +1,110 @@
+use windows::core::PCWSTR;
+use windows::Win32::Devices::FunctionDiscovery::PKEY_Device_FriendlyName;
+use windows::Win32::Media::Audio::{
+    eCommunications, eConsole, eMultimedia, eRender, IMMDevice, IMMDeviceEnumerator,
+    MMDeviceEnumerator, DEVICE_STATE_ACTIVE,
+};
+use windows::Win32::System::Com::{
+    CoCreateInstance, StructuredStorage::PropVariantToStringAlloc, CLSCTX_ALL, STGM_READ,
+};
+
+mod policy_config;
+
+use policy_config::IPolicyConfig;
+
+/// A request to swap the system default playback (and communications) endpoint
+/// from the desktop audio device to the couch one.
+pub struct SwapDefaultAudioEndpointsRequest<'a> {
+    pub desktop_audio_name: &'a str,
+    pub couch_audio_name: &'a str,
+}
+
+#[derive(Debug, Default)]
+pub struct SwapDefaultAudioEndpointsResponse {
+    pub new_default_endpoint: Option<String>,
+}
+
+pub struct AudioSettings;
+
+impl AudioSettings {
+    /// Switches the system default playback endpoint (console, multimedia and
+    /// communications roles) to the couch audio device, mirroring
+    /// `DisplaySettings::swap_primary_monitors`.
+    pub unsafe fn swap_default_audio_endpoints(
+        &self,
+        request: &SwapDefaultAudioEndpointsRequest,
+    ) -> Result<SwapDefaultAudioEndpointsResponse, String> {
+        let couch_endpoint_id = self.find_active_render_endpoint_id(request.couch_audio_name)?;
+
+        let policy_config: IPolicyConfig = CoCreateInstance(
+            &policy_config::CLSID_POLICY_CONFIG_CLIENT,
+            None,
+            CLSCTX_ALL,
+        )
+        .map_err(|error| format!("Could not create the policy config client ({error})"))?;
+
+        let couch_endpoint_id_wide = to_wide(&couch_endpoint_id);
+
+        for role in [eConsole, eMultimedia, eCommunications] {
+            policy_config
+                .set_default_endpoint(PCWSTR(couch_endpoint_id_wide.as_ptr()), role)
+                .map_err(|error| {
+                    format!("Could not set {} as the default audio endpoint ({error})", request.couch_audio_name)
+                })?;
+        }
+
+        Ok(SwapDefaultAudioEndpointsResponse {
+            new_default_endpoint: Some(request.couch_audio_name.to_owned()),
+        })
+    }
+
+    unsafe fn find_active_render_endpoint_id(&self, friendly_name: &str) -> Result<String, String> {
+        let enumerator: IMMDeviceEnumerator =
+            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)
+                .map_err(|error| format!("Could not create the device enumerator ({error})"))?;
+
+        let endpoints = enumerator
+            .EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE)
+            .map_err(|error| format!("Could not enumerate the render endpoints ({error})"))?;
+
+        let count = endpoints
+            .GetCount()
+            .map_err(|error| format!("Could not count the render endpoints ({error})"))?;
+
+        (0..count)
+            .filter_map(|index| endpoints.Item(index).ok())
+            .find_map(|device| {
+                let name = Self::friendly_name(&device).ok()?;
+                (name == friendly_name).then_some(device)
+            })
+            .ok_or_else(|| format!("Could not find an audio device named {friendly_name}"))
+            .and_then(|device| {
+                let id = device
+                    .GetId()
+                    .map_err(|error| format!("Could not read the id of {friendly_name} ({error})"))?;
+
+                id.to_string()
+                    .map_err(|error| format!("Could not decode the id of {friendly_name} ({error})"))
+            })
+    }
+
+    unsafe fn friendly_name(device: &IMMDevice) -> Result<String, String> {
+        let property_store = device
+            .OpenPropertyStore(STGM_READ)
+            .map_err(|error| format!("Could not open the property store of the device ({error})"))?;
+        let friendly_name = property_store
+            .GetValue(&PKEY_Device_FriendlyName)
+            .map_err(|error| format!("Could not read the friendly name of the device ({error})"))?;
+
+        let friendly_name = PropVariantToStringAlloc(&friendly_name)
+            .map_err(|error| format!("Could not read the friendly name of the device ({error})"))?;
+
+        friendly_name
+            .to_string()
+            .map_err(|error| format!("Could not decode the friendly name of the device ({error})"))
+    }
+}
+
+fn to_wide(value: &str) -> Vec<u16> {
+    value.encode_utf16().chain(std::iter::once(0)).collect()
+}
@@ -0,0 +1,24 @@
+use windows::core::{interface, GUID, HRESULT, PCWSTR};
+use windows::Win32::Media::Audio::ERole;
+
+/// Undocumented CLSID behind `IPolicyConfig`, used by every community tool that needs
+/// to change the default audio endpoint since Windows itself exposes no public API for it.
+pub const CLSID_POLICY_CONFIG_CLIENT: GUID = GUID::from_u128(0x870af99c_171d_4f9e_af0d_e63df40c2bc9);
+
+/// Undocumented, but stable since Vista, interface exposing `SetDefaultEndpoint`. Only
+/// the one method this crate needs is declared; the rest of the real vtable is skipped
+/// over by giving it the matching number of reserved slots.
+#[interface("f8679f50-850a-41cf-9c72-430f290290c8")]
+unsafe trait IPolicyConfig: windows::core::IUnknown {
+    fn reserved_01(&self) -> HRESULT;
+    fn reserved_02(&self) -> HRESULT;
+    fn reserved_03(&self) -> HRESULT;
+    fn reserved_04(&self) -> HRESULT;
+    fn reserved_05(&self) -> HRESULT;
+    fn reserved_06(&self) -> HRESULT;
+    fn reserved_07(&self) -> HRESULT;
+    fn reserved_08(&self) -> HRESULT;
+    fn reserved_09(&self) -> HRESULT;
+    fn reserved_10(&self) -> HRESULT;
+    fn set_default_endpoint(&self, device_id: PCWSTR, role: ERole) -> HRESULT;
+}
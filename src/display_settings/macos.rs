@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use core_graphics::display::{
+    CGBeginDisplayConfiguration, CGCompleteDisplayConfiguration, CGConfigureOption,
+    CGDisplayBounds, CGDisplayConfigRef, CGConfigureDisplayOrigin, CGGetActiveDisplayList,
+    CGMainDisplayID, CGDirectDisplayID,
+};
+
+use super::{CommitOutcome, DisplayBackend, Monitor, VideoMode};
+
+const MAX_DISPLAYS: u32 = 32;
+
+fn display_name(display_id: CGDirectDisplayID) -> String {
+    format!("Display {display_id}")
+}
+
+/// Core Graphics-backed [`DisplayBackend`]. The display whose bounds have origin
+/// (0, 0) is the primary one, so making the couch display primary means moving it
+/// to the origin and the desktop display to wherever the couch display used to be;
+/// both moves are staged in the same `CGDisplayConfigRef` transaction and applied
+/// together by `commit`.
+#[derive(Default)]
+pub struct MacOsBackend {
+    pending_positions: HashMap<CGDirectDisplayID, (i32, i32)>,
+}
+
+impl MacOsBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DisplayBackend for MacOsBackend {
+    unsafe fn monitors(&mut self) -> Result<Vec<Monitor>, String> {
+        let mut display_ids = vec![0 as CGDirectDisplayID; MAX_DISPLAYS as usize];
+        let mut display_count = 0u32;
+
+        if CGGetActiveDisplayList(MAX_DISPLAYS, display_ids.as_mut_ptr(), &mut display_count) != 0
+        {
+            return Err("Could not get the active display list".to_owned());
+        }
+
+        display_ids.truncate(display_count as usize);
+
+        Ok(display_ids
+            .into_iter()
+            .map(|display_id| {
+                let bounds = CGDisplayBounds(display_id);
+                let mode = VideoMode {
+                    width: bounds.size.width as u32,
+                    height: bounds.size.height as u32,
+                    refresh_rate_millihertz: 60_000,
+                    bit_depth: 32,
+                };
+
+                Monitor {
+                    id: display_id.to_string(),
+                    name: display_name(display_id),
+                    primary: display_id == CGMainDisplayID(),
+                    position: (bounds.origin.x as i32, bounds.origin.y as i32),
+                    mode,
+                    available_modes: vec![mode],
+                }
+            })
+            .collect())
+    }
+
+    unsafe fn set_position(
+        &mut self,
+        monitor_id: &str,
+        position: (i32, i32),
+    ) -> Result<(), String> {
+        let display_id = parse_display_id(monitor_id)?;
+        self.pending_positions.insert(display_id, position);
+        Ok(())
+    }
+
+    unsafe fn set_primary(&mut self, monitor_id: &str) -> Result<(), String> {
+        // Core Graphics has no separate "set primary" call: a display becomes primary
+        // by being moved to the origin, which `set_position` already stages.
+        let display_id = parse_display_id(monitor_id)?;
+        self.pending_positions
+            .entry(display_id)
+            .or_insert((0, 0));
+        Ok(())
+    }
+
+    unsafe fn set_mode(&mut self, _monitor_id: &str, _mode: VideoMode) -> Result<(), String> {
+        Err("Switching display modes is not yet supported on macOS".to_owned())
+    }
+
+    unsafe fn commit(&mut self) -> Result<CommitOutcome, String> {
+        if self.pending_positions.is_empty() {
+            return Ok(CommitOutcome::Applied);
+        }
+
+        let mut config: CGDisplayConfigRef = std::ptr::null_mut();
+
+        if CGBeginDisplayConfiguration(&mut config) != 0 {
+            return Err("Could not begin a display configuration transaction".to_owned());
+        }
+
+        for (&display_id, &position) in &self.pending_positions {
+            CGConfigureDisplayOrigin(config, display_id, position.0, position.1);
+        }
+
+        self.pending_positions.clear();
+
+        if CGCompleteDisplayConfiguration(config, CGConfigureOption::Permanently) != 0 {
+            return Err("Could not apply the pending display changes".to_owned());
+        }
+
+        Ok(CommitOutcome::Applied)
+    }
+}
+
+fn parse_display_id(monitor_id: &str) -> Result<CGDirectDisplayID, String> {
+    monitor_id
+        .parse()
+        .map_err(|_| format!("{monitor_id} is not a valid Core Graphics display id"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_position_and_set_primary_only_stage_pending_positions() {
+        let mut backend = MacOsBackend::default();
+
+        unsafe { backend.set_position("1", (100, 200)) }.unwrap();
+        unsafe { backend.set_primary("2") }.unwrap();
+
+        assert_eq!(backend.pending_positions.get(&1), Some(&(100, 200)));
+        assert_eq!(backend.pending_positions.get(&2), Some(&(0, 0)));
+    }
+
+    #[test]
+    fn set_primary_does_not_override_an_already_staged_position() {
+        let mut backend = MacOsBackend::default();
+
+        unsafe { backend.set_position("1", (100, 200)) }.unwrap();
+        unsafe { backend.set_primary("1") }.unwrap();
+
+        assert_eq!(backend.pending_positions.get(&1), Some(&(100, 200)));
+    }
+
+    #[test]
+    fn set_position_rejects_a_non_numeric_monitor_id() {
+        let mut backend = MacOsBackend::default();
+
+        assert!(unsafe { backend.set_position("not-a-display-id", (0, 0)) }.is_err());
+    }
+
+    #[test]
+    fn set_mode_is_not_yet_supported() {
+        let mut backend = MacOsBackend::default();
+        let mode = VideoMode {
+            width: 1920,
+            height: 1080,
+            refresh_rate_millihertz: 60_000,
+            bit_depth: 32,
+        };
+
+        assert!(unsafe { backend.set_mode("1", mode) }.is_err());
+    }
+
+    #[test]
+    fn commit_is_a_no_op_when_nothing_is_staged() {
+        let mut backend = MacOsBackend::default();
+
+        assert_eq!(unsafe { backend.commit() }.unwrap(), CommitOutcome::Applied);
+    }
+}
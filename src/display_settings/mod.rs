@@ -0,0 +1,395 @@
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(all(unix, not(target_os = "macos")))]
+mod x11;
+#[cfg(windows)]
+mod win32;
+
+#[cfg(target_os = "macos")]
+pub use macos::MacOsBackend;
+#[cfg(all(unix, not(target_os = "macos")))]
+pub use x11::X11Backend;
+#[cfg(windows)]
+pub use win32::Win32Backend;
+
+/// A mode a monitor can be driven at, mirroring how winit models `VideoMode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VideoMode {
+    pub width: u32,
+    pub height: u32,
+    pub refresh_rate_millihertz: u32,
+    pub bit_depth: u32,
+}
+
+impl VideoMode {
+    /// Coarse distance used to rank candidate modes against what the caller asked for.
+    /// Resolution dominates, refresh rate is a tie-breaker, bit depth barely matters.
+    fn distance_to(&self, other: &VideoMode) -> (u64, u32, u32) {
+        let width_diff = u64::from(self.width.abs_diff(other.width));
+        let height_diff = u64::from(self.height.abs_diff(other.height));
+        let refresh_rate_diff = self.refresh_rate_millihertz.abs_diff(other.refresh_rate_millihertz);
+        let bit_depth_diff = self.bit_depth.abs_diff(other.bit_depth);
+
+        (width_diff + height_diff, refresh_rate_diff, bit_depth_diff)
+    }
+
+    fn closest_match(wanted: &VideoMode, supported: &[VideoMode]) -> Option<VideoMode> {
+        supported
+            .iter()
+            .min_by_key(|candidate| wanted.distance_to(candidate))
+            .copied()
+    }
+}
+
+/// A monitor as reported by a [`DisplayBackend`], identified by a backend-specific id
+/// (a GDI device name, an XRandR output name, a `CGDirectDisplayID`, ...).
+#[derive(Debug, Clone)]
+pub struct Monitor {
+    pub id: String,
+    pub name: String,
+    pub primary: bool,
+    pub position: (i32, i32),
+    pub mode: VideoMode,
+    pub available_modes: Vec<VideoMode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitOutcome {
+    Applied,
+    RebootRequired,
+}
+
+/// The platform-specific operations `DisplaySettings` needs, so that the swap/list
+/// logic itself stays platform-agnostic. One implementation per windowing system:
+/// Win32 (GDI + `QueryDisplayConfig`), X11 (XRandR) and macOS (Core Graphics).
+pub trait DisplayBackend {
+    /// # Safety
+    /// Calls into the platform's windowing APIs (GDI, XRandR, Core Graphics), which
+    /// require a live connection to the display server/subsystem the backend opened.
+    unsafe fn monitors(&mut self) -> Result<Vec<Monitor>, String>;
+    /// # Safety
+    /// Stages the position against the platform's windowing APIs; see [`Self::monitors`].
+    unsafe fn set_position(&mut self, monitor_id: &str, position: (i32, i32)) -> Result<(), String>;
+    /// # Safety
+    /// Stages the primary flag against the platform's windowing APIs; see [`Self::monitors`].
+    unsafe fn set_primary(&mut self, monitor_id: &str) -> Result<(), String>;
+    /// # Safety
+    /// Stages the mode against the platform's windowing APIs; see [`Self::monitors`].
+    unsafe fn set_mode(&mut self, monitor_id: &str, mode: VideoMode) -> Result<(), String>;
+    /// # Safety
+    /// Applies every staged change through the platform's windowing APIs; see [`Self::monitors`].
+    unsafe fn commit(&mut self) -> Result<CommitOutcome, String>;
+}
+
+/// A request to swap the desktop and couch monitors, optionally pinning either one to
+/// a specific mode once the swap is committed.
+pub struct SwapPrimaryMonitorsRequest<'a> {
+    pub desktop_monitor_name: &'a str,
+    pub couch_monitor_name: &'a str,
+    pub desktop_monitor_mode: Option<VideoMode>,
+    pub couch_monitor_mode: Option<VideoMode>,
+}
+
+#[derive(Debug, Default)]
+pub struct SwapPrimaryMonitorsResponse {
+    pub new_primary: Option<String>,
+    pub new_primary_mode: Option<VideoMode>,
+    pub reboot_required: bool,
+}
+
+/// A connected monitor as reported by `list_monitors`, for users who don't yet know
+/// the exact name to pass to `--desktop-monitor-name`/`--couch-monitor-name`.
+#[derive(Debug, Clone)]
+pub struct MonitorInfo {
+    pub name: String,
+    pub primary: bool,
+    pub position: (i32, i32),
+    pub mode: VideoMode,
+}
+
+impl From<Monitor> for MonitorInfo {
+    fn from(monitor: Monitor) -> Self {
+        Self {
+            name: monitor.name,
+            primary: monitor.primary,
+            position: monitor.position,
+            mode: monitor.mode,
+        }
+    }
+}
+
+pub struct DisplaySettings<B: DisplayBackend> {
+    backend: B,
+}
+
+impl<B: DisplayBackend> DisplaySettings<B> {
+    pub fn new(backend: B) -> Self {
+        Self { backend }
+    }
+
+    /// Walks every connected monitor, skipping whatever the backend filters out
+    /// (inactive outputs, mirroring drivers, ...), and reports its readable name,
+    /// position and current mode.
+    ///
+    /// # Safety
+    /// See [`DisplayBackend::monitors`].
+    pub unsafe fn list_monitors(&mut self) -> Result<Vec<MonitorInfo>, String> {
+        Ok(self
+            .backend
+            .monitors()?
+            .into_iter()
+            .map(MonitorInfo::from)
+            .collect())
+    }
+
+    /// Swaps which of the two named monitors is primary (and its position with the
+    /// other one's), and stages each monitor's requested mode, if any, so that the
+    /// swap and the modes are applied by the same commit.
+    ///
+    /// # Safety
+    /// See [`DisplayBackend::monitors`].
+    pub unsafe fn swap_primary_monitors(
+        &mut self,
+        request: &SwapPrimaryMonitorsRequest,
+    ) -> Result<SwapPrimaryMonitorsResponse, String> {
+        let monitors = self.backend.monitors()?;
+
+        let desktop = Self::find_monitor(&monitors, request.desktop_monitor_name)?;
+        let couch = Self::find_monitor(&monitors, request.couch_monitor_name)?;
+
+        self.backend.set_position(&desktop.id, couch.position)?;
+        self.backend.set_position(&couch.id, desktop.position)?;
+        self.backend.set_primary(&couch.id)?;
+
+        self.apply_mode(desktop, request.desktop_monitor_mode)?;
+        let new_primary_mode = self.apply_mode(couch, request.couch_monitor_mode)?;
+
+        let reboot_required = matches!(self.backend.commit()?, CommitOutcome::RebootRequired);
+
+        Ok(SwapPrimaryMonitorsResponse {
+            new_primary: Some(request.couch_monitor_name.to_owned()),
+            new_primary_mode,
+            reboot_required,
+        })
+    }
+
+    fn find_monitor<'a>(monitors: &'a [Monitor], name: &str) -> Result<&'a Monitor, String> {
+        monitors
+            .iter()
+            .find(|monitor| monitor.name == name)
+            .ok_or_else(|| format!("Could not find a monitor named {name}"))
+    }
+
+    unsafe fn apply_mode(
+        &mut self,
+        monitor: &Monitor,
+        wanted_mode: Option<VideoMode>,
+    ) -> Result<Option<VideoMode>, String> {
+        match wanted_mode {
+            None => Ok(None),
+            Some(wanted_mode) => {
+                let closest_mode = VideoMode::closest_match(&wanted_mode, &monitor.available_modes)
+                    .unwrap_or(monitor.mode);
+
+                self.backend.set_mode(&monitor.id, closest_mode)?;
+
+                Ok(Some(closest_mode))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+    use super::*;
+
+    const COMMON_REFRESH_RATES_HZ: [u32; 3] = [60, 120, 144];
+    const COMMON_BIT_DEPTHS: [u32; 2] = [24, 32];
+
+    /// Fuzzes a realistic list of modes a monitor could report for `width`/`height`:
+    /// every common refresh rate/bit depth combination, shuffled so tests can't rely
+    /// on the exact match happening to be first.
+    fn fuzz_available_modes(rand: &mut StdRng, width: u32, height: u32) -> Vec<VideoMode> {
+        let mut modes: Vec<VideoMode> = COMMON_REFRESH_RATES_HZ
+            .iter()
+            .flat_map(|&refresh_rate_hz| {
+                COMMON_BIT_DEPTHS.iter().map(move |&bit_depth| VideoMode {
+                    width,
+                    height,
+                    refresh_rate_millihertz: refresh_rate_hz * 1000,
+                    bit_depth,
+                })
+            })
+            .collect();
+
+        modes.shuffle(rand);
+
+        modes
+    }
+
+    /// [`DisplayBackend`] fuzzing double: stages changes into plain fields instead of
+    /// touching any real windowing API, so `DisplaySettings`'s mode-switching logic can
+    /// be exercised without a real display driver.
+    #[derive(Clone, Default)]
+    struct FuzzedBackend {
+        monitors: Vec<Monitor>,
+        /// Error the next `commit` should fail with, so tests can exercise the
+        /// failure path without a real windowing API to refuse the change.
+        commit_error: Option<String>,
+    }
+
+    impl DisplayBackend for FuzzedBackend {
+        unsafe fn monitors(&mut self) -> Result<Vec<Monitor>, String> {
+            Ok(self.monitors.clone())
+        }
+
+        unsafe fn set_position(&mut self, monitor_id: &str, position: (i32, i32)) -> Result<(), String> {
+            self.monitor_mut(monitor_id)?.position = position;
+            Ok(())
+        }
+
+        unsafe fn set_primary(&mut self, monitor_id: &str) -> Result<(), String> {
+            for monitor in &mut self.monitors {
+                monitor.primary = monitor.id == monitor_id;
+            }
+            Ok(())
+        }
+
+        unsafe fn set_mode(&mut self, monitor_id: &str, mode: VideoMode) -> Result<(), String> {
+            self.monitor_mut(monitor_id)?.mode = mode;
+            Ok(())
+        }
+
+        unsafe fn commit(&mut self) -> Result<CommitOutcome, String> {
+            match self.commit_error.take() {
+                Some(message) => Err(message),
+                None => Ok(CommitOutcome::Applied),
+            }
+        }
+    }
+
+    impl FuzzedBackend {
+        fn monitor_mut(&mut self, monitor_id: &str) -> Result<&mut Monitor, String> {
+            self.monitors
+                .iter_mut()
+                .find(|monitor| monitor.id == monitor_id)
+                .ok_or_else(|| format!("No fuzzed monitor with id {monitor_id}"))
+        }
+    }
+
+    fn fuzzed_monitor(id: &str, name: &str, primary: bool, available_modes: Vec<VideoMode>) -> Monitor {
+        Monitor {
+            id: id.to_owned(),
+            name: name.to_owned(),
+            primary,
+            position: if primary { (0, 0) } else { (1, 0) },
+            mode: available_modes[0],
+            available_modes,
+        }
+    }
+
+    #[test]
+    fn swap_primary_monitors_applies_the_closest_supported_mode_to_each_monitor() {
+        for seed in 0..32 {
+            let mut rand = StdRng::seed_from_u64(seed);
+
+            let desktop_modes = fuzz_available_modes(&mut rand, 2560, 1440);
+            let couch_modes = fuzz_available_modes(&mut rand, 3840, 2160);
+
+            let backend = FuzzedBackend {
+                monitors: vec![
+                    fuzzed_monitor("1", "Desktop", true, desktop_modes.clone()),
+                    fuzzed_monitor("2", "Couch", false, couch_modes.clone()),
+                ],
+                ..Default::default()
+            };
+
+            let wanted_couch_mode = *couch_modes.choose(&mut rand).unwrap();
+
+            let mut display_settings = DisplaySettings::new(backend);
+            let request = SwapPrimaryMonitorsRequest {
+                desktop_monitor_name: "Desktop",
+                couch_monitor_name: "Couch",
+                desktop_monitor_mode: None,
+                couch_monitor_mode: Some(wanted_couch_mode),
+            };
+
+            let response = unsafe { display_settings.swap_primary_monitors(&request).unwrap() };
+
+            assert_eq!(response.new_primary, Some("Couch".to_owned()));
+            assert_eq!(response.new_primary_mode, Some(wanted_couch_mode));
+
+            let monitors = unsafe { display_settings.list_monitors().unwrap() };
+            let couch = monitors.iter().find(|monitor| monitor.name == "Couch").unwrap();
+            assert_eq!(couch.mode, wanted_couch_mode);
+            assert!(couch.primary);
+        }
+    }
+
+    #[test]
+    fn apply_mode_falls_back_to_the_current_mode_when_the_backend_reports_none_supported() {
+        for seed in 0..32 {
+            let mut rand = StdRng::seed_from_u64(seed);
+
+            let monitor = Monitor {
+                id: "1".to_owned(),
+                name: "Desktop".to_owned(),
+                primary: true,
+                position: (0, 0),
+                mode: VideoMode {
+                    width: 1920,
+                    height: 1080,
+                    refresh_rate_millihertz: 60_000,
+                    bit_depth: 24,
+                },
+                available_modes: vec![],
+            };
+
+            let backend = FuzzedBackend {
+                monitors: vec![monitor.clone()],
+                ..Default::default()
+            };
+            let mut display_settings = DisplaySettings::new(backend);
+
+            let wanted_mode = VideoMode {
+                width: rand.gen_range(640..7680),
+                height: rand.gen_range(480..4320),
+                refresh_rate_millihertz: 240_000,
+                bit_depth: 32,
+            };
+
+            let applied_mode =
+                unsafe { display_settings.apply_mode(&monitor, Some(wanted_mode)).unwrap() };
+
+            assert_eq!(applied_mode, Some(monitor.mode));
+        }
+    }
+
+    #[test]
+    fn swap_primary_monitors_surfaces_an_error_when_the_backend_refuses_to_commit() {
+        let backend = FuzzedBackend {
+            monitors: vec![
+                fuzzed_monitor("1", "Desktop", true, fuzz_available_modes(&mut StdRng::seed_from_u64(0), 2560, 1440)),
+                fuzzed_monitor("2", "Couch", false, fuzz_available_modes(&mut StdRng::seed_from_u64(1), 3840, 2160)),
+            ],
+            commit_error: Some("the driver refused the mode change".to_owned()),
+        };
+
+        let mut display_settings = DisplaySettings::new(backend);
+        let request = SwapPrimaryMonitorsRequest {
+            desktop_monitor_name: "Desktop",
+            couch_monitor_name: "Couch",
+            desktop_monitor_mode: None,
+            couch_monitor_mode: None,
+        };
+
+        let result = unsafe { display_settings.swap_primary_monitors(&request) };
+
+        assert_eq!(
+            result.unwrap_err(),
+            "the driver refused the mode change".to_owned()
+        );
+    }
+}
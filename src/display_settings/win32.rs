@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+use std::mem::size_of;
+
+use windows::core::PCWSTR;
+use windows::Win32::Devices::Display::{
+    DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes, QueryDisplayConfig,
+    DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_PATH_INFO,
+    DISPLAYCONFIG_TARGET_DEVICE_NAME, QDC_ONLY_ACTIVE_PATHS,
+};
+use windows::Win32::Graphics::Gdi::{
+    ChangeDisplaySettingsExW, EnumDisplayDevicesW, EnumDisplaySettingsExW, CDS_NORESET,
+    CDS_SET_PRIMARY, CDS_UPDATEREGISTRY, DEVMODEW, DISP_CHANGE, DISPLAY_DEVICEW,
+    DISPLAY_DEVICE_ACTIVE, DISPLAY_DEVICE_MIRRORING_DRIVER, DM_BITSPERPEL, DM_DISPLAYFREQUENCY,
+    DM_PELSHEIGHT, DM_PELSWIDTH, DM_POSITION, ENUM_CURRENT_SETTINGS, ENUM_DISPLAY_SETTINGS_MODE,
+};
+
+use super::{CommitOutcome, DisplayBackend, Monitor, VideoMode};
+
+fn to_wide(value: &str) -> Vec<u16> {
+    value.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+fn device_string(raw: &[u16]) -> String {
+    String::from_utf16_lossy(raw)
+        .trim_end_matches('\0')
+        .to_owned()
+}
+
+fn video_mode_from_dev_mode(dev_mode: &DEVMODEW) -> VideoMode {
+    VideoMode {
+        width: dev_mode.dmPelsWidth,
+        height: dev_mode.dmPelsHeight,
+        refresh_rate_millihertz: dev_mode.dmDisplayFrequency * 1000,
+        bit_depth: dev_mode.dmBitsPerPel,
+    }
+}
+
+/// Resolves the human-friendly monitor name (e.g. "DELL U2720Q") for a GDI device path,
+/// since `EnumDisplayDevicesW`'s `DeviceString` is the adapter name, not the monitor's.
+unsafe fn query_display_config_target_name(device_path: &str) -> Option<String> {
+    let mut n_path_arrays = 0u32;
+    let mut n_mode_info_arrays = 0u32;
+
+    GetDisplayConfigBufferSizes(
+        QDC_ONLY_ACTIVE_PATHS,
+        &mut n_path_arrays,
+        &mut n_mode_info_arrays,
+    )
+    .ok()?;
+
+    let mut paths: Vec<DISPLAYCONFIG_PATH_INFO> =
+        vec![DISPLAYCONFIG_PATH_INFO::default(); n_path_arrays as usize];
+    let mut modes: Vec<DISPLAYCONFIG_MODE_INFO> =
+        vec![DISPLAYCONFIG_MODE_INFO::default(); n_mode_info_arrays as usize];
+
+    QueryDisplayConfig(
+        QDC_ONLY_ACTIVE_PATHS,
+        &mut n_path_arrays,
+        paths.as_mut_ptr(),
+        &mut n_mode_info_arrays,
+        modes.as_mut_ptr(),
+        None,
+    )
+    .ok()?;
+
+    paths.truncate(n_path_arrays as usize);
+
+    paths.iter().find_map(|path| {
+        let mut target_name = DISPLAYCONFIG_TARGET_DEVICE_NAME {
+            header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+                size: size_of::<DISPLAYCONFIG_TARGET_DEVICE_NAME>() as u32,
+                adapterId: path.targetInfo.adapterId,
+                id: path.targetInfo.id,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        if DisplayConfigGetDeviceInfo(&mut target_name.header) != 0 {
+            return None;
+        }
+
+        let monitor_device_path = device_string(&target_name.monitorDevicePath);
+
+        (monitor_device_path == device_path)
+            .then(|| device_string(&target_name.monitorFriendlyDeviceName))
+            .filter(|name| !name.is_empty())
+    })
+}
+
+unsafe fn enum_display_devices() -> Vec<DISPLAY_DEVICEW> {
+    let mut devices = vec![];
+    let mut device_index = 0;
+
+    loop {
+        let mut device = DISPLAY_DEVICEW {
+            cb: size_of::<DISPLAY_DEVICEW>() as u32,
+            ..Default::default()
+        };
+
+        if !EnumDisplayDevicesW(None, device_index, &mut device, 0).as_bool() {
+            break;
+        }
+
+        devices.push(device);
+        device_index += 1;
+    }
+
+    devices
+}
+
+unsafe fn enum_display_settings(device_name: &str) -> Option<DEVMODEW> {
+    let device_name_wide = to_wide(device_name);
+    let mut dev_mode = DEVMODEW {
+        dmSize: size_of::<DEVMODEW>() as u16,
+        ..Default::default()
+    };
+
+    EnumDisplaySettingsExW(
+        PCWSTR(device_name_wide.as_ptr()),
+        ENUM_CURRENT_SETTINGS,
+        &mut dev_mode,
+        0,
+    )
+    .as_bool()
+    .then_some(dev_mode)
+}
+
+/// Walks every mode index `EnumDisplaySettingsExW` reports for `device_name` (0, 1, 2, …
+/// until it returns false), so callers can pick the closest match to a requested mode
+/// instead of only ever seeing the monitor's current one.
+unsafe fn enum_all_display_settings(device_name: &str) -> Vec<DEVMODEW> {
+    let device_name_wide = to_wide(device_name);
+    let mut modes = vec![];
+    let mut mode_index = 0u32;
+
+    loop {
+        let mut dev_mode = DEVMODEW {
+            dmSize: size_of::<DEVMODEW>() as u16,
+            ..Default::default()
+        };
+
+        if !EnumDisplaySettingsExW(
+            PCWSTR(device_name_wide.as_ptr()),
+            ENUM_DISPLAY_SETTINGS_MODE(mode_index),
+            &mut dev_mode,
+            0,
+        )
+        .as_bool()
+        {
+            break;
+        }
+
+        modes.push(dev_mode);
+        mode_index += 1;
+    }
+
+    modes
+}
+
+/// GDI + `QueryDisplayConfig`-backed [`DisplayBackend`]. Changes staged through
+/// `set_position`/`set_primary`/`set_mode` are buffered in `pending` and only reach
+/// the driver once `commit` calls `ChangeDisplaySettingsExW(None, ...)`.
+#[derive(Default)]
+pub struct Win32Backend {
+    pending: HashMap<String, DEVMODEW>,
+    pending_primary: Option<String>,
+}
+
+impl Win32Backend {
+    fn pending_dev_mode(&mut self, monitor_id: &str) -> Result<&mut DEVMODEW, String> {
+        if !self.pending.contains_key(monitor_id) {
+            let dev_mode = unsafe { enum_display_settings(monitor_id) }
+                .ok_or_else(|| format!("Could not read the current settings of {monitor_id}"))?;
+
+            self.pending.insert(monitor_id.to_owned(), dev_mode);
+        }
+
+        Ok(self.pending.get_mut(monitor_id).unwrap())
+    }
+}
+
+impl DisplayBackend for Win32Backend {
+    unsafe fn monitors(&mut self) -> Result<Vec<Monitor>, String> {
+        enum_display_devices()
+            .into_iter()
+            .filter(|device| {
+                device.StateFlags & DISPLAY_DEVICE_ACTIVE != 0
+                    && device.StateFlags & DISPLAY_DEVICE_MIRRORING_DRIVER == 0
+            })
+            .map(|device| {
+                let device_name = device_string(&device.DeviceName);
+                let device_path = device_string(&device.DeviceID);
+                let dev_mode = enum_display_settings(&device_name)
+                    .ok_or_else(|| format!("Could not read the current settings of {device_name}"))?;
+                let position = dev_mode.Anonymous1.Anonymous2.dmPosition;
+                let mode = video_mode_from_dev_mode(&dev_mode);
+                let mut available_modes: Vec<VideoMode> = enum_all_display_settings(&device_name)
+                    .iter()
+                    .map(video_mode_from_dev_mode)
+                    .collect();
+
+                if !available_modes.contains(&mode) {
+                    available_modes.push(mode);
+                }
+
+                // Every caller (the swap and the list-monitors discovery path alike) goes
+                // through this same `name`, so whatever `list-monitors` prints is always
+                // exactly what `--desktop-monitor-name`/`--couch-monitor-name` expect; fall
+                // back past the adapter string to the device path itself rather than risk
+                // an empty, uncopiable name.
+                let name = query_display_config_target_name(&device_path)
+                    .filter(|name| !name.is_empty())
+                    .unwrap_or_else(|| {
+                        let adapter_name = device_string(&device.DeviceString);
+
+                        if adapter_name.is_empty() {
+                            device_path.clone()
+                        } else {
+                            adapter_name
+                        }
+                    });
+
+                Ok(Monitor {
+                    id: device_name,
+                    name,
+                    primary: position.x == 0 && position.y == 0,
+                    position: (position.x, position.y),
+                    available_modes,
+                    mode,
+                })
+            })
+            .collect()
+    }
+
+    unsafe fn set_position(
+        &mut self,
+        monitor_id: &str,
+        position: (i32, i32),
+    ) -> Result<(), String> {
+        let dev_mode = self.pending_dev_mode(monitor_id)?;
+
+        dev_mode.Anonymous1.Anonymous2.dmPosition.x = position.0;
+        dev_mode.Anonymous1.Anonymous2.dmPosition.y = position.1;
+        dev_mode.dmFields |= DM_POSITION;
+
+        Ok(())
+    }
+
+    unsafe fn set_primary(&mut self, monitor_id: &str) -> Result<(), String> {
+        self.pending_dev_mode(monitor_id)?;
+        self.pending_primary = Some(monitor_id.to_owned());
+
+        Ok(())
+    }
+
+    unsafe fn set_mode(&mut self, monitor_id: &str, mode: VideoMode) -> Result<(), String> {
+        let dev_mode = self.pending_dev_mode(monitor_id)?;
+
+        dev_mode.dmPelsWidth = mode.width;
+        dev_mode.dmPelsHeight = mode.height;
+        dev_mode.dmDisplayFrequency = mode.refresh_rate_millihertz / 1000;
+        dev_mode.dmBitsPerPel = mode.bit_depth;
+        dev_mode.dmFields |= DM_PELSWIDTH | DM_PELSHEIGHT | DM_DISPLAYFREQUENCY | DM_BITSPERPEL;
+
+        Ok(())
+    }
+
+    unsafe fn commit(&mut self) -> Result<CommitOutcome, String> {
+        for (monitor_id, dev_mode) in self.pending.iter_mut() {
+            let device_name_wide = to_wide(monitor_id);
+            let mut flags = CDS_NORESET | CDS_UPDATEREGISTRY;
+
+            if self.pending_primary.as_deref() == Some(monitor_id.as_str()) {
+                flags |= CDS_SET_PRIMARY;
+            }
+
+            match ChangeDisplaySettingsExW(
+                PCWSTR(device_name_wide.as_ptr()),
+                Some(dev_mode),
+                None,
+                flags,
+                None,
+            ) {
+                DISP_CHANGE::DISP_CHANGE_SUCCESSFUL | DISP_CHANGE::DISP_CHANGE_RESTART => {}
+                error => return Err(format!("Could not switch {monitor_id} ({error:?})")),
+            }
+        }
+
+        self.pending.clear();
+        self.pending_primary = None;
+
+        match ChangeDisplaySettingsExW(PCWSTR::null(), None, None, Default::default(), None) {
+            DISP_CHANGE::DISP_CHANGE_SUCCESSFUL => Ok(CommitOutcome::Applied),
+            DISP_CHANGE::DISP_CHANGE_RESTART => Ok(CommitOutcome::RebootRequired),
+            error => Err(format!("Could not apply the pending display changes ({error:?})")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn device_string_trims_the_trailing_nul_padding() {
+        let raw = to_wide("DELL U2720Q");
+
+        assert_eq!(device_string(&raw), "DELL U2720Q");
+    }
+
+    #[test]
+    fn device_string_is_empty_for_an_all_nul_buffer() {
+        let raw = [0u16; 32];
+
+        assert_eq!(device_string(&raw), "");
+    }
+
+    #[test]
+    fn video_mode_from_dev_mode_converts_every_field() {
+        let dev_mode = DEVMODEW {
+            dmPelsWidth: 3840,
+            dmPelsHeight: 2160,
+            dmDisplayFrequency: 60,
+            dmBitsPerPel: 32,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            video_mode_from_dev_mode(&dev_mode),
+            VideoMode {
+                width: 3840,
+                height: 2160,
+                refresh_rate_millihertz: 60_000,
+                bit_depth: 32,
+            }
+        );
+    }
+}
@@ -0,0 +1,428 @@
+use std::collections::{HashMap, HashSet};
+use std::ffi::CStr;
+use std::ptr;
+
+use x11::xlib::{XCloseDisplay, XDefaultRootWindow, XOpenDisplay, XSync, Display as XDisplay};
+use x11::xrandr::{
+    XRRCrtcInfo, XRRFreeCrtcInfo, XRRFreeOutputInfo, XRRFreeScreenResources, XRRGetCrtcInfo,
+    XRRGetOutputInfo, XRRGetOutputPrimary, XRRGetScreenResources, XRROutputInfo,
+    XRRScreenResources, XRRSetCrtcConfig, XRRSetOutputPrimary, RR_Connected,
+};
+
+use super::{CommitOutcome, DisplayBackend, Monitor, VideoMode};
+
+fn output_name(output_info: &XRROutputInfo) -> String {
+    unsafe {
+        CStr::from_ptr(output_info.name)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// XRandR-backed [`DisplayBackend`]. Position/primary/mode changes are staged against
+/// the CRTC each output is driving and only committed with `XRRSetCrtcConfig`/
+/// `XRRSetOutputPrimary` once `commit` runs, mirroring how the Win32 backend batches
+/// `DEVMODEW` changes before a single `ChangeDisplaySettingsExW` pass.
+pub struct X11Backend {
+    display: *mut XDisplay,
+    pending_positions: HashMap<u64, (i32, i32)>,
+    pending_modes: HashMap<u64, VideoMode>,
+    pending_primary: Option<u64>,
+}
+
+impl X11Backend {
+    pub fn new() -> Result<Self, String> {
+        let display = unsafe { XOpenDisplay(ptr::null()) };
+
+        if display.is_null() {
+            return Err("Could not open the X11 display".to_owned());
+        }
+
+        Ok(Self {
+            display,
+            pending_positions: HashMap::new(),
+            pending_modes: HashMap::new(),
+            pending_primary: None,
+        })
+    }
+}
+
+impl Drop for X11Backend {
+    fn drop(&mut self) {
+        unsafe { XCloseDisplay(self.display) };
+    }
+}
+
+impl DisplayBackend for X11Backend {
+    unsafe fn monitors(&mut self) -> Result<Vec<Monitor>, String> {
+        let root = XDefaultRootWindow(self.display);
+        let resources = XRRGetScreenResources(self.display, root);
+
+        if resources.is_null() {
+            return Err("Could not get the XRandR screen resources".to_owned());
+        }
+
+        let primary_output = XRRGetOutputPrimary(self.display, root);
+        let outputs = std::slice::from_raw_parts((*resources).outputs, (*resources).noutput as usize);
+
+        let monitors = outputs
+            .iter()
+            .filter_map(|&output| {
+                let output_info = XRRGetOutputInfo(self.display, resources, output);
+
+                if output_info.is_null() || (*output_info).connection != RR_Connected as u16 {
+                    if !output_info.is_null() {
+                        XRRFreeOutputInfo(output_info);
+                    }
+                    return None;
+                }
+
+                let crtc = (*output_info).crtc;
+                let crtc_info = XRRGetCrtcInfo(self.display, resources, crtc);
+                let name = output_name(&*output_info);
+
+                let available_modes = output_modes(&*output_info, &*resources);
+
+                let monitor = (!crtc_info.is_null()).then(|| {
+                    let mode = find_mode_info(&*resources, (*crtc_info).mode)
+                        .map(video_mode_from_mode_info)
+                        .unwrap_or(VideoMode {
+                            width: (*crtc_info).width,
+                            height: (*crtc_info).height,
+                            refresh_rate_millihertz: 60_000,
+                            bit_depth: 24,
+                        });
+
+                    Monitor {
+                        id: output.to_string(),
+                        name: name.clone(),
+                        primary: output == primary_output,
+                        position: ((*crtc_info).x, (*crtc_info).y),
+                        mode,
+                        available_modes: available_modes.clone(),
+                    }
+                });
+
+                if !crtc_info.is_null() {
+                    XRRFreeCrtcInfo(crtc_info);
+                }
+                XRRFreeOutputInfo(output_info);
+
+                monitor
+            })
+            .collect();
+
+        XRRFreeScreenResources(resources);
+
+        Ok(monitors)
+    }
+
+    unsafe fn set_position(
+        &mut self,
+        monitor_id: &str,
+        position: (i32, i32),
+    ) -> Result<(), String> {
+        let output = parse_output_id(monitor_id)?;
+        self.pending_positions.insert(output, position);
+        Ok(())
+    }
+
+    unsafe fn set_primary(&mut self, monitor_id: &str) -> Result<(), String> {
+        self.pending_primary = Some(parse_output_id(monitor_id)?);
+        Ok(())
+    }
+
+    unsafe fn set_mode(&mut self, monitor_id: &str, mode: VideoMode) -> Result<(), String> {
+        let output = parse_output_id(monitor_id)?;
+        self.pending_modes.insert(output, mode);
+        Ok(())
+    }
+
+    unsafe fn commit(&mut self) -> Result<CommitOutcome, String> {
+        let root = XDefaultRootWindow(self.display);
+        let resources = XRRGetScreenResources(self.display, root);
+
+        if resources.is_null() {
+            return Err("Could not get the XRandR screen resources".to_owned());
+        }
+
+        let staged_outputs: HashSet<u64> = self
+            .pending_positions
+            .keys()
+            .chain(self.pending_modes.keys())
+            .copied()
+            .collect();
+
+        for output in staged_outputs {
+            let output_info = XRRGetOutputInfo(self.display, resources, output);
+
+            if !output_info.is_null() {
+                let crtc = (*output_info).crtc;
+                let crtc_info: *mut XRRCrtcInfo = XRRGetCrtcInfo(self.display, resources, crtc);
+
+                if !crtc_info.is_null() {
+                    let position = self
+                        .pending_positions
+                        .get(&output)
+                        .copied()
+                        .unwrap_or(((*crtc_info).x, (*crtc_info).y));
+                    let mode_id = self
+                        .pending_modes
+                        .get(&output)
+                        .map_or((*crtc_info).mode, |mode| {
+                            resolve_mode_id(&*resources, mode, (*crtc_info).mode)
+                        });
+
+                    XRRSetCrtcConfig(
+                        self.display,
+                        resources,
+                        crtc,
+                        x11::xlib::CurrentTime,
+                        position.0,
+                        position.1,
+                        mode_id,
+                        (*crtc_info).rotation,
+                        (*crtc_info).outputs,
+                        (*crtc_info).noutput,
+                    );
+                    XRRFreeCrtcInfo(crtc_info);
+                }
+
+                XRRFreeOutputInfo(output_info);
+            }
+        }
+
+        if let Some(primary) = self.pending_primary {
+            XRRSetOutputPrimary(self.display, root, primary);
+        }
+
+        XRRFreeScreenResources(resources);
+        XSync(self.display, 0);
+
+        self.pending_positions.clear();
+        self.pending_modes.clear();
+        self.pending_primary = None;
+
+        Ok(CommitOutcome::Applied)
+    }
+}
+
+/// Resolves the `XRRModeInfo`s an output actually advertises (`output_info.modes`)
+/// against the screen-wide mode table (`resources.modes`), the same table
+/// `resolve_mode_id` scans when committing a mode change.
+unsafe fn output_modes(output_info: &XRROutputInfo, resources: &XRRScreenResources) -> Vec<VideoMode> {
+    let mode_ids = std::slice::from_raw_parts(output_info.modes, output_info.nmode as usize);
+
+    mode_ids
+        .iter()
+        .filter_map(|&mode_id| find_mode_info(resources, mode_id))
+        .map(video_mode_from_mode_info)
+        .collect()
+}
+
+unsafe fn find_mode_info(resources: &XRRScreenResources, mode_id: u64) -> Option<x11::xrandr::XRRModeInfo> {
+    let modes = std::slice::from_raw_parts(resources.modes, resources.nmode as usize);
+
+    modes.iter().find(|mode_info| mode_info.id == mode_id).copied()
+}
+
+fn video_mode_from_mode_info(mode_info: x11::xrandr::XRRModeInfo) -> VideoMode {
+    VideoMode {
+        width: mode_info.width,
+        height: mode_info.height,
+        refresh_rate_millihertz: refresh_rate_millihertz(&mode_info).max(0) as u32,
+        bit_depth: 24,
+    }
+}
+
+fn parse_output_id(monitor_id: &str) -> Result<u64, String> {
+    monitor_id
+        .parse()
+        .map_err(|_| format!("{monitor_id} is not a valid XRandR output id"))
+}
+
+/// Looks up the `XRRModeInfo` matching `mode`'s resolution and refresh rate among the
+/// ones XRandR already knows about, since `XRRSetCrtcConfig` takes a mode id rather than
+/// a width/height/refresh triple. Falls back to `fallback` (the CRTC's current mode) if
+/// none match closely enough, which only happens for resolutions the driver never
+/// reported in the first place.
+unsafe fn resolve_mode_id(resources: &XRRScreenResources, mode: &VideoMode, fallback: u64) -> u64 {
+    let modes = std::slice::from_raw_parts(resources.modes, resources.nmode as usize);
+
+    modes
+        .iter()
+        .filter(|mode_info| mode_info.width == mode.width && mode_info.height == mode.height)
+        .min_by_key(|mode_info| {
+            let refresh_rate_millihertz = refresh_rate_millihertz(mode_info);
+            (refresh_rate_millihertz - mode.refresh_rate_millihertz as i64).abs()
+        })
+        .map_or(fallback, |mode_info| mode_info.id)
+}
+
+fn refresh_rate_millihertz(mode_info: &x11::xrandr::XRRModeInfo) -> i64 {
+    let h_total = mode_info.hTotal as f64;
+    let v_total = mode_info.vTotal as f64;
+
+    if h_total == 0.0 || v_total == 0.0 {
+        return 0;
+    }
+
+    ((mode_info.dotClock as f64 / (h_total * v_total)) * 1000.0).round() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use x11::xrandr::XRRModeInfo;
+
+    use super::*;
+
+    /// Builds a synthetic `XRRModeInfo`, the same way XRandR itself would populate one
+    /// in `resources.modes`, without needing a real X server to ask for one.
+    fn mode_info(id: u64, width: u32, height: u32, h_total: u32, v_total: u32, dot_clock: u64) -> XRRModeInfo {
+        XRRModeInfo {
+            id,
+            width,
+            height,
+            dotClock: dot_clock,
+            hSyncStart: 0,
+            hSyncEnd: 0,
+            hTotal: h_total,
+            hSkew: 0,
+            vSyncStart: 0,
+            vSyncEnd: 0,
+            vTotal: v_total,
+            name: std::ptr::null_mut(),
+            nameLength: 0,
+            modeFlags: 0,
+        }
+    }
+
+    fn screen_resources(modes: &mut [XRRModeInfo]) -> XRRScreenResources {
+        XRRScreenResources {
+            timestamp: 0,
+            configTimestamp: 0,
+            ncrtc: 0,
+            crtcs: std::ptr::null_mut(),
+            noutput: 0,
+            outputs: std::ptr::null_mut(),
+            nmode: modes.len() as i32,
+            modes: modes.as_mut_ptr(),
+        }
+    }
+
+    #[test]
+    fn refresh_rate_millihertz_computes_from_the_dot_clock_and_totals() {
+        // 3840x2160 @ 60Hz: dotClock / (hTotal * vTotal) * 1000.
+        let mode = mode_info(1, 3840, 2160, 4400, 2250, 594_000_000);
+        assert_eq!(refresh_rate_millihertz(&mode), 60_000);
+    }
+
+    #[test]
+    fn refresh_rate_millihertz_is_zero_when_the_totals_are_zero() {
+        let mode = mode_info(1, 0, 0, 0, 0, 0);
+        assert_eq!(refresh_rate_millihertz(&mode), 0);
+    }
+
+    #[test]
+    fn resolve_mode_id_picks_the_closest_refresh_rate_at_the_requested_resolution() {
+        let mut modes = vec![
+            mode_info(10, 1920, 1080, 2200, 1125, 148_500_000), // 60Hz
+            mode_info(11, 1920, 1080, 2200, 1125, 297_000_000), // 120Hz
+            mode_info(12, 2560, 1440, 2720, 1481, 241_773_300), // a different resolution entirely
+        ];
+        let resources = screen_resources(&mut modes);
+
+        let wanted = VideoMode {
+            width: 1920,
+            height: 1080,
+            refresh_rate_millihertz: 100_000,
+            bit_depth: 24,
+        };
+
+        let resolved = unsafe { resolve_mode_id(&resources, &wanted, 999) };
+
+        assert_eq!(resolved, 11);
+    }
+
+    #[test]
+    fn resolve_mode_id_falls_back_when_the_resolution_is_not_reported() {
+        let mut modes = vec![mode_info(10, 2560, 1440, 2720, 1481, 241_773_300)];
+        let resources = screen_resources(&mut modes);
+
+        let wanted = VideoMode {
+            width: 1920,
+            height: 1080,
+            refresh_rate_millihertz: 60_000,
+            bit_depth: 24,
+        };
+
+        let resolved = unsafe { resolve_mode_id(&resources, &wanted, 42) };
+
+        assert_eq!(resolved, 42);
+    }
+
+    #[test]
+    fn find_mode_info_looks_up_by_id() {
+        let mut modes = vec![
+            mode_info(10, 1920, 1080, 2200, 1125, 148_500_000),
+            mode_info(11, 3840, 2160, 4400, 2250, 594_000_000),
+        ];
+        let resources = screen_resources(&mut modes);
+
+        let found = unsafe { find_mode_info(&resources, 11) }.unwrap();
+
+        assert_eq!(found.width, 3840);
+        assert_eq!(found.height, 2160);
+
+        assert!(unsafe { find_mode_info(&resources, 404) }.is_none());
+    }
+
+    #[test]
+    fn set_position_set_primary_and_set_mode_only_stage_pending_changes() {
+        let mut backend = X11Backend {
+            display: std::ptr::null_mut(),
+            pending_positions: HashMap::new(),
+            pending_modes: HashMap::new(),
+            pending_primary: None,
+        };
+
+        unsafe { backend.set_position("7", (100, 200)) }.unwrap();
+        unsafe { backend.set_primary("7") }.unwrap();
+        unsafe {
+            backend.set_mode(
+                "7",
+                VideoMode {
+                    width: 1920,
+                    height: 1080,
+                    refresh_rate_millihertz: 60_000,
+                    bit_depth: 24,
+                },
+            )
+        }
+        .unwrap();
+
+        assert_eq!(backend.pending_positions.get(&7), Some(&(100, 200)));
+        assert_eq!(backend.pending_primary, Some(7));
+        assert_eq!(backend.pending_modes.get(&7).unwrap().width, 1920);
+
+        // `display` is a null stand-in with no real connection behind it; skip
+        // `Drop`'s `XCloseDisplay` call rather than hand it a null pointer.
+        std::mem::forget(backend);
+    }
+
+    #[test]
+    fn set_position_rejects_a_non_numeric_monitor_id() {
+        let mut backend = X11Backend {
+            display: std::ptr::null_mut(),
+            pending_positions: HashMap::new(),
+            pending_modes: HashMap::new(),
+            pending_primary: None,
+        };
+
+        assert!(unsafe { backend.set_position("not-an-output-id", (0, 0)) }.is_err());
+
+        // `display` is a null stand-in with no real connection behind it; skip
+        // `Drop`'s `XCloseDisplay` call rather than hand it a null pointer.
+        std::mem::forget(backend);
+    }
+}
@@ -0,0 +1,4 @@
+#[cfg(windows)]
+pub mod audio_settings;
+pub mod display_settings;
+pub mod log;
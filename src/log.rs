@@ -0,0 +1,31 @@
+use clap::ValueEnum;
+use log::LevelFilter;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => LevelFilter::Error,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Debug => LevelFilter::Debug,
+            LogLevel::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+pub fn configure_logger(level: LogLevel) {
+    env_logger::Builder::new()
+        .filter_level(level.into())
+        .format_timestamp(None)
+        .format_target(false)
+        .init();
+}
@@ -1,45 +1,282 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use convertible_couch::{
-    display_settings::{DisplaySettings, Win32DevicesDisplayImpl, Win32GraphicsGdiImpl},
+    display_settings::{DisplayBackend, DisplaySettings, SwapPrimaryMonitorsRequest, VideoMode},
     log::{configure_logger, LogLevel},
 };
 use log::{error, info, warn};
 
+#[cfg(windows)]
+use convertible_couch::display_settings::Win32Backend;
+#[cfg(target_os = "macos")]
+use convertible_couch::display_settings::MacOsBackend;
+#[cfg(all(unix, not(target_os = "macos")))]
+use convertible_couch::display_settings::X11Backend;
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    #[arg(short, long)]
-    desktop_monitor_name: String,
-    #[arg(short, long)]
-    couch_monitor_name: String,
-    #[arg(short, long, value_enum, default_value_t = LogLevel::Info)]
+    #[command(subcommand)]
+    command: Command,
+    #[arg(short, long, value_enum, default_value_t = LogLevel::Info, global = true)]
     log_level: LogLevel,
 }
 
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Swap which monitor is primary, switching it with the couch monitor
+    Swap {
+        #[arg(short, long)]
+        desktop_monitor_name: String,
+        #[arg(short, long)]
+        couch_monitor_name: String,
+        /// Resolution to switch the desktop monitor to as part of the swap, e.g. "2560x1440@144"
+        #[arg(long, value_parser = parse_video_mode)]
+        desktop_monitor_mode: Option<VideoMode>,
+        /// Resolution to switch the couch monitor to once it becomes primary, e.g. "3840x2160@60"
+        #[arg(long, value_parser = parse_video_mode)]
+        couch_monitor_mode: Option<VideoMode>,
+        /// Name of the desktop audio device; the audio swap runs only when this and --couch-audio-name are both given, and it never makes this device the default endpoint
+        #[arg(long)]
+        desktop_audio_name: Option<String>,
+        /// Name of the audio device to switch the default endpoint to once the couch monitor becomes primary
+        #[arg(long)]
+        couch_audio_name: Option<String>,
+    },
+    /// List every connected monitor with its readable name, position and current mode
+    ListMonitors,
+}
+
+fn parse_video_mode(value: &str) -> Result<VideoMode, String> {
+    let (resolution, refresh_rate) = value
+        .split_once('@')
+        .ok_or_else(|| format!("{value} is not a valid mode, expected WIDTHxHEIGHT@REFRESH_RATE"))?;
+    let (width, height) = resolution
+        .split_once('x')
+        .ok_or_else(|| format!("{resolution} is not a valid resolution, expected WIDTHxHEIGHT"))?;
+
+    Ok(VideoMode {
+        width: width
+            .parse()
+            .map_err(|_| format!("{width} is not a valid width"))?,
+        height: height
+            .parse()
+            .map_err(|_| format!("{height} is not a valid height"))?,
+        refresh_rate_millihertz: refresh_rate
+            .parse::<u32>()
+            .map_err(|_| format!("{refresh_rate} is not a valid refresh rate"))?
+            * 1000,
+        bit_depth: 32,
+    })
+}
+
+#[cfg(windows)]
+fn build_display_settings() -> Result<DisplaySettings<Win32Backend>, String> {
+    Ok(DisplaySettings::new(Win32Backend::default()))
+}
+
+#[cfg(target_os = "macos")]
+fn build_display_settings() -> Result<DisplaySettings<MacOsBackend>, String> {
+    Ok(DisplaySettings::new(MacOsBackend::new()))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn build_display_settings() -> Result<DisplaySettings<X11Backend>, String> {
+    Ok(DisplaySettings::new(X11Backend::new()?))
+}
+
 fn main() {
     let args: Args = Args::parse();
 
     configure_logger(args.log_level);
 
-    let win32_devices_display = Win32DevicesDisplayImpl;
-    let win32_graphics_gdi = Win32GraphicsGdiImpl;
-    let display_settings = DisplaySettings::new(win32_devices_display, win32_graphics_gdi);
+    let mut display_settings = match build_display_settings() {
+        Ok(display_settings) => display_settings,
+        Err(message) => {
+            error!("{}", message);
+            return;
+        }
+    };
+
+    match args.command {
+        Command::Swap {
+            desktop_monitor_name,
+            couch_monitor_name,
+            desktop_monitor_mode,
+            couch_monitor_mode,
+            desktop_audio_name,
+            couch_audio_name,
+        } => {
+            let monitors_swapped = swap(
+                &mut display_settings,
+                &desktop_monitor_name,
+                &couch_monitor_name,
+                desktop_monitor_mode,
+                couch_monitor_mode,
+            );
+
+            if monitors_swapped {
+                swap_audio(desktop_audio_name, couch_audio_name);
+            }
+        }
+        Command::ListMonitors => print_monitors(&mut display_settings),
+    }
+}
+
+/// Swaps the primary monitor and reports whether it actually happened, so callers
+/// can decide whether dependent changes (like the audio swap) should run at all.
+fn swap<B: DisplayBackend>(
+    display_settings: &mut DisplaySettings<B>,
+    desktop_monitor_name: &str,
+    couch_monitor_name: &str,
+    desktop_monitor_mode: Option<VideoMode>,
+    couch_monitor_mode: Option<VideoMode>,
+) -> bool {
+    let request = SwapPrimaryMonitorsRequest {
+        desktop_monitor_name,
+        couch_monitor_name,
+        desktop_monitor_mode,
+        couch_monitor_mode,
+    };
 
     unsafe {
-        match display_settings
-            .swap_primary_monitors(&args.desktop_monitor_name, &args.couch_monitor_name)
-        {
+        match display_settings.swap_primary_monitors(&request) {
             Ok(response) => {
-                match response.new_primary {
-                    Some(new_primary) => info!("Primary monitor set to {}", new_primary),
-                    None => error!("Primary monitor has not been changed for an unknow reason"),
-                }
+                let swapped = match (&response.new_primary, &response.new_primary_mode) {
+                    (Some(new_primary), Some(mode)) => {
+                        info!(
+                            "Primary monitor set to {} at {}x{}@{}mHz",
+                            new_primary, mode.width, mode.height, mode.refresh_rate_millihertz
+                        );
+                        true
+                    }
+                    (Some(new_primary), None) => {
+                        info!("Primary monitor set to {}", new_primary);
+                        true
+                    }
+                    (None, _) => {
+                        error!("Primary monitor has not been changed for an unknow reason");
+                        false
+                    }
+                };
 
                 if response.reboot_required {
                     warn!("The settings change was successful but the computer must be restarted for the graphics mode to work.");
                 }
+
+                swapped
+            }
+            Err(message) => {
+                error!("{}", message);
+                false
             }
+        }
+    }
+}
+
+/// Switches the default audio endpoint to the couch audio device alongside the
+/// monitor swap, when both the desktop and the couch audio device are named; a
+/// no-op otherwise. The desktop audio device is never made the default endpoint.
+fn swap_audio(desktop_audio_name: Option<String>, couch_audio_name: Option<String>) {
+    let (Some(desktop_audio_name), Some(couch_audio_name)) = (desktop_audio_name, couch_audio_name)
+    else {
+        return;
+    };
+
+    #[cfg(windows)]
+    {
+        use convertible_couch::audio_settings::{AudioSettings, SwapDefaultAudioEndpointsRequest};
+
+        let request = SwapDefaultAudioEndpointsRequest {
+            desktop_audio_name: &desktop_audio_name,
+            couch_audio_name: &couch_audio_name,
+        };
+
+        match unsafe { AudioSettings.swap_default_audio_endpoints(&request) } {
+            Ok(response) => match response.new_default_endpoint {
+                Some(new_default_endpoint) => {
+                    info!("Default audio endpoint set to {}", new_default_endpoint)
+                }
+                None => error!("Default audio endpoint has not been changed for an unknow reason"),
+            },
             Err(message) => error!("{}", message),
         }
     }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (desktop_audio_name, couch_audio_name);
+        warn!("Swapping the default audio endpoint is only supported on Windows");
+    }
+}
+
+fn print_monitors<B: DisplayBackend>(display_settings: &mut DisplaySettings<B>) {
+    let monitors = match unsafe { display_settings.list_monitors() } {
+        Ok(monitors) => monitors,
+        Err(message) => {
+            error!("{}", message);
+            return;
+        }
+    };
+
+    println!(
+        "{:<30} {:<10} {:<15} {:<10}",
+        "NAME", "PRIMARY", "POSITION", "MODE"
+    );
+
+    for monitor in monitors {
+        println!(
+            "{:<30} {:<10} {:<15} {}x{}@{}mHz",
+            monitor.name,
+            if monitor.primary { "yes" } else { "" },
+            format!("{}, {}", monitor.position.0, monitor.position.1),
+            monitor.mode.width,
+            monitor.mode.height,
+            monitor.mode.refresh_rate_millihertz
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_video_mode_accepts_a_well_formed_mode() {
+        let mode = parse_video_mode("2560x1440@144").unwrap();
+
+        assert_eq!(
+            mode,
+            VideoMode {
+                width: 2560,
+                height: 1440,
+                refresh_rate_millihertz: 144_000,
+                bit_depth: 32,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_video_mode_rejects_a_missing_at_sign() {
+        assert!(parse_video_mode("2560x1440").is_err());
+    }
+
+    #[test]
+    fn parse_video_mode_rejects_a_missing_x() {
+        assert!(parse_video_mode("2560@144").is_err());
+    }
+
+    #[test]
+    fn parse_video_mode_rejects_a_non_numeric_width() {
+        assert!(parse_video_mode("widex1440@144").is_err());
+    }
+
+    #[test]
+    fn parse_video_mode_rejects_a_non_numeric_height() {
+        assert!(parse_video_mode("2560xtall@144").is_err());
+    }
+
+    #[test]
+    fn parse_video_mode_rejects_a_non_numeric_refresh_rate() {
+        assert!(parse_video_mode("2560x1440@fast").is_err());
+    }
 }